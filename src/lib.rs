@@ -1,5 +1,7 @@
 //! This crate's API is not sound. So deprecating it.
 
+#![no_std]
+
 #[cfg(test)]
 mod tests {
 